@@ -26,7 +26,36 @@ pub trait ChunkIter<T, I: Iterator<Item = T>> {
     /// assert_eq!(chunks.next(), Some([3, 4, 5]));
     /// assert_eq!(chunks.next(), None);
     /// ```
+    ///
+    /// A chunk size of zero is a compile error:
+    /// ```compile_fail
+    /// use chunk_iter::ChunkIter;
+    ///
+    /// let iter = vec![0, 1, 2].into_iter();
+    /// let _ = iter.chunks::<0>();
+    /// ```
     fn chunks<const N: usize>(self) -> Chunks<T, I, N>;
+
+    /// Make overlapping windows:
+    /// ```
+    /// use chunk_iter::ChunkIter;
+    ///
+    /// let iter = vec![0, 1, 2, 3].into_iter();
+    /// let mut windows = iter.windows::<2>();
+    /// assert_eq!(windows.next(), Some([0, 1]));
+    /// assert_eq!(windows.next(), Some([1, 2]));
+    /// assert_eq!(windows.next(), Some([2, 3]));
+    /// assert_eq!(windows.next(), None);
+    /// ```
+    ///
+    /// Like [`chunks`](Self::chunks), a window size of zero is a compile error:
+    /// ```compile_fail
+    /// use chunk_iter::ChunkIter;
+    ///
+    /// let iter = vec![0, 1, 2].into_iter();
+    /// let _ = iter.windows::<0>();
+    /// ```
+    fn windows<const N: usize>(self) -> Windows<T, I, N>;
 }
 
 impl<T, I> ChunkIter<T, I> for I
@@ -34,10 +63,24 @@ where
     I: Iterator<Item = T>,
 {
     fn chunks<const N: usize>(self) -> Chunks<T, I, N> {
+        // Force the compile-time check that the chunk size is non-zero.
+        let () = Chunks::<T, I, N>::ASSERT_NON_ZERO;
         Chunks {
             buffer: unsafe { MaybeUninit::uninit().assume_init() },
             iterator: self,
             needs_dropping: 0,
+            back_aligned: false,
+        }
+    }
+
+    fn windows<const N: usize>(self) -> Windows<T, I, N> {
+        // Force the compile-time check that the window size is non-zero.
+        let () = Windows::<T, I, N>::ASSERT_NON_ZERO;
+        Windows {
+            buffer: unsafe { MaybeUninit::uninit().assume_init() },
+            iterator: self,
+            needs_dropping: 0,
+            primed: false,
         }
     }
 }
@@ -46,10 +89,17 @@ pub struct Chunks<T, I: Iterator<Item = T>, const N: usize> {
     buffer: [MaybeUninit<T>; N],
     iterator: I,
     needs_dropping: usize,
+    /// Whether the back of the inner iterator has been trimmed to a chunk
+    /// boundary yet. Only touched by `next_back`; see its documentation.
+    back_aligned: bool,
 }
 
 impl<T, I: Iterator<Item = T>, const N: usize> Chunks<T, I, N> {
     const NONE: Option<T> = None;
+    /// Compile-time guard that the chunk size is non-zero. A chunk size of `0`
+    /// would make `next` an infinite iterator of empty arrays, so reject it up
+    /// front like the standard library's `array_chunks` adapter does.
+    const ASSERT_NON_ZERO: () = assert!(N != 0, "chunk size must be non-zero");
     /// Gets the number of currently stored things in the backing array.
     /// This is usually empty, and only will have values after the backing iterator runs out.
     /// ```
@@ -85,6 +135,37 @@ impl<T, I: Iterator<Item = T>, const N: usize> Chunks<T, I, N> {
         unsafe { ptr::drop_in_place(&mut this.iterator) };
         stored
     }
+
+    /// Consume the `Chunks` and return an owning iterator over only the leftover
+    /// tail elements — the ones held back because they did not fill a whole
+    /// chunk. Unlike [`into_stored`](Self::into_stored) there is no `None`
+    /// padding: the returned iterator yields exactly the stored elements, by
+    /// value, in order.
+    /// ```
+    /// use chunk_iter::ChunkIter;
+    ///
+    /// let mut iter = vec![0, 1, 2, 3, 4].into_iter().chunks::<3>();
+    /// assert_eq!(iter.next(), Some([0, 1, 2]));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let remainder: Vec<_> = iter.into_remainder().collect();
+    /// assert_eq!(remainder, [3, 4]);
+    /// ```
+    pub fn into_remainder(self) -> Remainder<T, N> {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `buffer` is read out by value and its drop responsibility is
+        // handed to the returned `Remainder`; the source is `ManuallyDrop` so it
+        // will not drop the slots a second time.
+        let buffer = unsafe { ptr::read(&this.buffer) };
+        let end = this.needs_dropping;
+        // The inner iterator is no longer needed, so drop it in place.
+        unsafe { ptr::drop_in_place(&mut this.iterator) };
+        Remainder {
+            buffer,
+            start: 0,
+            end,
+        }
+    }
 }
 
 impl<T, I: Iterator<Item = T>, const N: usize> Iterator for Chunks<T, I, N> {
@@ -106,7 +187,80 @@ impl<T, I: Iterator<Item = T>, const N: usize> Iterator for Chunks<T, I, N> {
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let (lower, upper) = self.iterator.size_hint();
-        (lower / N, upper.map(|x| x / N))
+        // Fold any already-buffered elements into the bounds: they count towards
+        // the next chunk along with what the source still yields.
+        (
+            (lower + self.needs_dropping) / N,
+            upper.map(|x| (x + self.needs_dropping) / N),
+        )
+    }
+
+    fn count(self) -> usize {
+        // Drain the inner iterator directly instead of materializing every
+        // `[T; N]` just to drop it.
+        let this = ManuallyDrop::new(self);
+        let needs_dropping = this.needs_dropping;
+        // SAFETY: the inner iterator is read out by value exactly once; `this`
+        // is `ManuallyDrop` so it is not dropped again.
+        let iterator = unsafe { ptr::read(&this.iterator) };
+        for x in 0..needs_dropping {
+            // SAFETY: needs_dropping only counts initialized slots. Reading drops
+            // the buffered element.
+            unsafe { this.buffer[x].as_ptr().read() };
+        }
+        (iterator.count() + needs_dropping) / N
+    }
+
+    // `try_fold` is deliberately not overridden: its signature names the unstable
+    // `core::ops::Try` trait, which cannot be implemented on stable. Driving the
+    // inner iterator's `fold` below still lets the compiler fuse chunk assembly
+    // with the source's internal iteration, which is the win we care about.
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        // `Chunks` has a `Drop` impl, so the buffer can't be moved out directly.
+        // Hold it in a small guard whose own `Drop` cleans up any partial fill —
+        // including when the user closure panics part way through the fold.
+        struct Guard<T, const N: usize> {
+            buffer: [MaybeUninit<T>; N],
+            needs_dropping: usize,
+        }
+        impl<T, const N: usize> Drop for Guard<T, N> {
+            fn drop(&mut self) {
+                for x in 0..self.needs_dropping {
+                    // SAFETY: needs_dropping only counts initialized slots.
+                    unsafe { self.buffer[x].as_ptr().read() };
+                }
+            }
+        }
+
+        let this = ManuallyDrop::new(self);
+        // SAFETY: both fields are read out by value exactly once; `this` is
+        // `ManuallyDrop` so it will not drop them again.
+        let iterator = unsafe { ptr::read(&this.iterator) };
+        let mut guard = Guard::<T, N> {
+            buffer: unsafe { ptr::read(&this.buffer) },
+            needs_dropping: this.needs_dropping,
+        };
+
+        iterator.fold(init, |acc, item| {
+            // SAFETY: needs_dropping < N here, as in `next`.
+            guard.buffer[guard.needs_dropping] = MaybeUninit::new(item);
+            guard.needs_dropping += 1;
+            if guard.needs_dropping == N {
+                // Reset before handing the chunk to the user: if `f` panics, the
+                // chunk is owned by `chunk` (dropped while unwinding) and the
+                // guard has nothing left to clean up.
+                guard.needs_dropping = 0;
+                // SAFETY: the whole buffer is initialized, and
+                // MaybeUninit<T> has the same layout as T.
+                let chunk = unsafe { core::mem::transmute_copy(&guard.buffer) };
+                f(acc, chunk)
+            } else {
+                acc
+            }
+        })
     }
 }
 impl<T, I: Iterator<Item = T> + ExactSizeIterator, const N: usize> ExactSizeIterator
@@ -116,6 +270,41 @@ impl<T, I: Iterator<Item = T> + ExactSizeIterator, const N: usize> ExactSizeIter
         self.iterator.len() / N
     }
 }
+impl<T, I, const N: usize> DoubleEndedIterator for Chunks<T, I, N>
+where
+    I: Iterator<Item = T> + ExactSizeIterator + DoubleEndedIterator,
+{
+    /// Yield a whole chunk from the back of the source.
+    ///
+    /// The trailing `len() % N` elements belong to no complete front-aligned
+    /// chunk, so the first back-call drops them to align the back end; each call
+    /// thereafter pulls `N` elements off the back. Mixing `next` and `next_back`
+    /// therefore shrinks the source from both ends, and the front-held `buffer`
+    /// state is independent of this back-alignment bookkeeping.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if !self.back_aligned {
+            // Drop the trailing partial group so the back is chunk-aligned.
+            let rem = self.iterator.len() % N;
+            for _ in 0..rem {
+                self.iterator.next_back();
+            }
+            self.back_aligned = true;
+        }
+        if self.iterator.len() < N {
+            return None;
+        }
+        let mut buffer: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        // Elements arrive back-to-front, so fill positions N-1..=0 to keep the
+        // emitted array in source order.
+        for slot in buffer.iter_mut().rev() {
+            // SAFETY: len() >= N was just checked, so next_back yields Some.
+            *slot = MaybeUninit::new(unsafe { self.iterator.next_back().unwrap_unchecked() });
+        }
+        // SAFETY: every slot was just initialized, and MaybeUninit<T> shares T's
+        // layout.
+        Some(unsafe { core::mem::transmute_copy(&buffer) })
+    }
+}
 impl<T, I: Iterator<Item = T> + FusedIterator, const N: usize> FusedIterator for Chunks<T, I, N> {}
 
 impl<T, I: Iterator<Item = T>, const N: usize> Drop for Chunks<T, I, N> {
@@ -127,6 +316,130 @@ impl<T, I: Iterator<Item = T>, const N: usize> Drop for Chunks<T, I, N> {
     }
 }
 
+/// Overlapping window iterator, return value of `.windows()`.
+pub struct Windows<T, I: Iterator<Item = T>, const N: usize> {
+    buffer: [MaybeUninit<T>; N],
+    iterator: I,
+    needs_dropping: usize,
+    primed: bool,
+}
+
+impl<T, I: Iterator<Item = T>, const N: usize> Windows<T, I, N> {
+    /// Compile-time guard that the window size is non-zero, mirroring
+    /// [`Chunks::ASSERT_NON_ZERO`].
+    const ASSERT_NON_ZERO: () = assert!(N != 0, "window size must be non-zero");
+}
+
+impl<T: Clone, I: Iterator<Item = T>, const N: usize> Windows<T, I, N> {
+    /// Clone the fully-initialized buffer into an owned `[T; N]`.
+    fn clone_window(&self) -> [T; N] {
+        // SAFETY: only called once the buffer is full, so every slot is
+        // initialized. `from_fn` drops the already-built elements if a `clone`
+        // panics, so no leak occurs on unwind.
+        core::array::from_fn(|i| unsafe { (*self.buffer[i].as_ptr()).clone() })
+    }
+}
+
+impl<T: Clone, I: Iterator<Item = T>, const N: usize> Iterator for Windows<T, I, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.primed {
+            // Prime with the first N elements. If the source is shorter the
+            // partial fill is left for `Drop`; `needs_dropping` is preserved so a
+            // retry on a fused source does not re-store anything.
+            while self.needs_dropping < N {
+                // SAFETY: needs_dropping < N, so the index is in bounds.
+                self.buffer[self.needs_dropping] = MaybeUninit::new(self.iterator.next()?);
+                self.needs_dropping += 1;
+            }
+            self.primed = true;
+            return Some(self.clone_window());
+        }
+        let next = self.iterator.next()?;
+        // Drop the oldest slot, shift the rest down, and append the new element.
+        // SAFETY: the whole buffer is initialized here (needs_dropping == N).
+        unsafe { self.buffer[0].as_ptr().read() };
+        for i in 1..N {
+            self.buffer[i - 1] = unsafe { ptr::read(&self.buffer[i]) };
+        }
+        self.buffer[N - 1] = MaybeUninit::new(next);
+        Some(self.clone_window())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+        if self.primed {
+            // The first window has already been emitted and the buffer holds no
+            // pending window, so exactly one window remains per source element.
+            (lower, upper)
+        } else {
+            // N elements are consumed before the first window can be emitted.
+            (
+                lower.saturating_sub(N - 1),
+                upper.map(|x| x.saturating_sub(N - 1)),
+            )
+        }
+    }
+}
+
+impl<T: Clone, I: Iterator<Item = T> + FusedIterator, const N: usize> FusedIterator
+    for Windows<T, I, N>
+{
+}
+
+impl<T, I: Iterator<Item = T>, const N: usize> Drop for Windows<T, I, N> {
+    fn drop(&mut self) {
+        for x in 0..self.needs_dropping {
+            // SAFETY: needs_dropping only includes values that are initialized.
+            unsafe { self.buffer[x].as_ptr().read() };
+        }
+    }
+}
+
+/// Owning iterator over the leftover tail elements of a [`Chunks`].
+///
+/// Returned by [`Chunks::into_remainder`]. Yields the stored elements by value,
+/// in source order, and drops any it does not yield.
+pub struct Remainder<T, const N: usize> {
+    buffer: [MaybeUninit<T>; N],
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> Iterator for Remainder<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        // SAFETY: `start..end` stays within the initialized prefix of the
+        // buffer, and each slot is read at most once as `start` advances.
+        let item = unsafe { self.buffer[self.start].as_ptr().read() };
+        self.start += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Remainder<T, N> {}
+impl<T, const N: usize> FusedIterator for Remainder<T, N> {}
+
+impl<T, const N: usize> Drop for Remainder<T, N> {
+    fn drop(&mut self) {
+        for x in self.start..self.end {
+            // SAFETY: the `start..end` range is exactly the not-yet-yielded
+            // initialized slots.
+            unsafe { self.buffer[x].as_ptr().read() };
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -166,6 +479,41 @@ mod tests {
         assert_eq!(iter.size_hint(), (2, Some(2)))
     }
 
+    #[test]
+    fn count_test() {
+        let iter = alloc::vec![0, 1, 2, 3, 4, 5, 6, 7]
+            .into_iter()
+            .chunks::<3>();
+        assert_eq!(iter.count(), 2);
+    }
+
+    #[test]
+    fn count_with_buffered_test() {
+        let mut iter = alloc::vec![0, 1, 2, 3, 4].into_iter().chunks::<3>();
+        iter.next();
+        // The second call buffers the trailing 3 and 4 before returning None.
+        assert!(iter.next().is_none());
+        // Two elements are buffered; the size hint and count both account for them.
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.count(), 0);
+    }
+
+    #[test]
+    fn count_drop_test() {
+        let test_drop = TestDrop::new();
+        let mut chunks = (0..5)
+            .map(|_| test_drop.new_item().1)
+            .collect::<alloc::vec::Vec<_>>()
+            .into_iter()
+            .chunks::<3>();
+
+        chunks.next();
+        assert!(chunks.next().is_none());
+        // `count` must still drop the two buffered trailing elements.
+        assert_eq!(chunks.count(), 0);
+        assert_eq!(5, test_drop.num_dropped_items());
+    }
+
     #[test]
     fn currently_stored_test() {
         let mut iter = alloc::vec![0, 1, 2, 3, 4].into_iter().chunks::<3>();
@@ -175,4 +523,116 @@ mod tests {
         assert_eq!(iter.currently_stored(), &[3, 4]);
         assert_eq!(iter.into_stored(), [Some(3), Some(4), None]);
     }
+
+    #[test]
+    fn into_remainder_test() {
+        let mut iter = alloc::vec![0, 1, 2, 3, 4].into_iter().chunks::<3>();
+        assert_eq!(iter.next(), Some([0, 1, 2]));
+        assert!(iter.next().is_none());
+        let remainder = iter.into_remainder().collect::<alloc::vec::Vec<_>>();
+        assert_eq!(remainder, alloc::vec![3, 4]);
+    }
+
+    #[test]
+    fn into_remainder_drop_test() {
+        let test_drop = TestDrop::new();
+        let mut chunks = (0..5)
+            .map(|_| test_drop.new_item().1)
+            .collect::<alloc::vec::Vec<_>>()
+            .into_iter()
+            .chunks::<3>();
+
+        chunks.next();
+        assert!(chunks.next().is_none());
+        // Dropping the remainder without consuming it must drop the stored items.
+        drop(chunks.into_remainder());
+
+        assert_eq!(5, test_drop.num_dropped_items());
+    }
+
+    #[test]
+    fn fold_test() {
+        let sum = alloc::vec![0, 1, 2, 3, 4, 5, 6, 7]
+            .into_iter()
+            .chunks::<3>()
+            .fold(alloc::vec::Vec::new(), |mut acc, chunk| {
+                acc.push(chunk);
+                acc
+            });
+        assert_eq!(sum, alloc::vec![[0, 1, 2], [3, 4, 5]]);
+    }
+
+    #[test]
+    fn fold_drop_test() {
+        let test_drop = TestDrop::new();
+        let chunks = (0..8)
+            .map(|_| test_drop.new_item().1)
+            .collect::<alloc::vec::Vec<_>>()
+            .into_iter()
+            .chunks::<3>();
+
+        // Two whole chunks are emitted; the two trailing items stay buffered and
+        // must be dropped when the fold consumes the iterator.
+        chunks.fold(0usize, |acc, _chunk| acc + 1);
+
+        assert_eq!(8, test_drop.num_dropped_items());
+    }
+
+    #[test]
+    fn next_back_test() {
+        let mut chunks = alloc::vec![0, 1, 2, 3, 4, 5, 6, 7].into_iter().chunks::<3>();
+        // The trailing `8 % 3 == 2` elements (6, 7) are dropped to align.
+        assert_eq!(chunks.next_back(), Some([3, 4, 5]));
+        assert_eq!(chunks.next_back(), Some([0, 1, 2]));
+        assert_eq!(chunks.next_back(), None);
+    }
+
+    #[test]
+    fn both_ends_test() {
+        let mut chunks = alloc::vec![0, 1, 2, 3, 4, 5, 6, 7, 8].into_iter().chunks::<3>();
+        assert_eq!(chunks.next(), Some([0, 1, 2]));
+        // `9 % 3 == 0`, so nothing is trimmed from the back.
+        assert_eq!(chunks.next_back(), Some([6, 7, 8]));
+        assert_eq!(chunks.next(), Some([3, 4, 5]));
+        assert_eq!(chunks.next(), None);
+    }
+
+    #[test]
+    fn windows_test() {
+        let mut windows = alloc::vec![0, 1, 2, 3].into_iter().windows::<2>();
+        assert_eq!(windows.next(), Some([0, 1]));
+        assert_eq!(windows.next(), Some([1, 2]));
+        assert_eq!(windows.next(), Some([2, 3]));
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    fn windows_size_hint_test() {
+        let mut windows = alloc::vec![0, 1, 2, 3].into_iter().windows::<2>();
+        // Before priming: 4 - (2 - 1) = 3 windows.
+        assert_eq!(windows.size_hint(), (3, Some(3)));
+        assert_eq!(windows.next(), Some([0, 1]));
+        // After priming only [1, 2] and [2, 3] remain.
+        assert_eq!(windows.size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    fn windows_short_source_test() {
+        let mut windows = alloc::vec![0, 1].into_iter().windows::<3>();
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    fn windows_drop_test() {
+        let test_drop = TestDrop::new();
+        let windows = (0..5)
+            .map(|_| test_drop.new_item().1)
+            .collect::<alloc::vec::Vec<_>>()
+            .into_iter()
+            .windows::<3>();
+
+        drop(windows);
+
+        assert_eq!(5, test_drop.num_tracked_items());
+    }
 }